@@ -0,0 +1,41 @@
+//! Optional backing-track playback for karaoke mode, started once at launch
+//! so it stays in sync with the [`crate::timeline::Timeline`] clock.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+
+/// Holds the live audio output so it keeps playing until the player drops.
+/// `_stream`/`_stream_handle` have no methods we call, but they must stay
+/// alive for as long as `sink` does or playback stops immediately.
+pub struct AudioPlayer {
+    _stream: OutputStream,
+    _stream_handle: OutputStreamHandle,
+    sink: Sink,
+}
+
+impl AudioPlayer {
+    /// Decodes `path` and starts playing it immediately on a fresh output
+    /// stream.
+    pub fn play_file(path: &Path) -> io::Result<Self> {
+        let (stream, stream_handle) =
+            OutputStream::try_default().map_err(io::Error::other)?;
+        let sink = Sink::try_new(&stream_handle).map_err(io::Error::other)?;
+
+        let file = File::open(path)?;
+        let source = Decoder::new(BufReader::new(file)).map_err(io::Error::other)?;
+        sink.append(source);
+
+        Ok(Self {
+            _stream: stream,
+            _stream_handle: stream_handle,
+            sink,
+        })
+    }
+
+    pub fn stop(&self) {
+        self.sink.stop();
+    }
+}
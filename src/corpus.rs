@@ -0,0 +1,229 @@
+//! Loads practice text from external files instead of a single baked-in poem.
+//!
+//! A corpus file holds one or more documents. Within a file, each
+//! non-blank source line becomes one practice line, and a blank line
+//! separates one document from the next, so a single file can bundle
+//! several short pieces.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// One loaded practice text: a display name plus the lines the player types.
+///
+/// `timestamps[i]` holds the karaoke cue time for `lines[i]`, parsed from an
+/// optional leading `[mm:ss.mmm]` tag; lines without a tag carry `None` and
+/// are only ever played back sequentially.
+#[derive(Debug, Clone)]
+pub struct Document {
+    pub name: String,
+    pub lines: Vec<String>,
+    pub timestamps: Vec<Option<Duration>>,
+}
+
+impl Document {
+    /// Whether any line in this document carries a karaoke timestamp.
+    pub fn has_timestamps(&self) -> bool {
+        self.timestamps.iter().any(Option::is_some)
+    }
+}
+
+/// Default directory scanned for `.txt` corpus files when none are given
+/// explicitly on the command line.
+pub const DEFAULT_CORPUS_DIR: &str = "corpus";
+
+/// Loads documents from explicit file paths, falling back to every `.txt`
+/// file under `config_dir` when `paths` is empty.
+pub fn load_documents(paths: &[String], config_dir: &Path) -> io::Result<Vec<Document>> {
+    let mut documents = Vec::new();
+
+    if paths.is_empty() {
+        for path in discover_corpus_files(config_dir)? {
+            documents.extend(parse_file(&path)?);
+        }
+    } else {
+        for path in paths {
+            documents.extend(parse_file(Path::new(path))?);
+        }
+    }
+
+    Ok(documents)
+}
+
+/// Lists `.txt` files directly inside `config_dir`, sorted by file name.
+fn discover_corpus_files(config_dir: &Path) -> io::Result<Vec<PathBuf>> {
+    if !config_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut files: Vec<PathBuf> = fs::read_dir(config_dir)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("txt"))
+        .collect();
+
+    files.sort();
+    Ok(files)
+}
+
+/// Splits a file's contents into one or more [`Document`]s on blank lines.
+fn parse_file(path: &Path) -> io::Result<Vec<Document>> {
+    let contents = fs::read_to_string(path)?;
+    let stem = path
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("corpus")
+        .to_string();
+
+    let mut documents = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    let mut timestamps: Vec<Option<Duration>> = Vec::new();
+    let mut block = 0usize;
+
+    for raw_line in contents.lines() {
+        let line = raw_line.trim_end();
+        if line.is_empty() {
+            if !lines.is_empty() {
+                documents.push(Document {
+                    name: document_name(&stem, block),
+                    lines: std::mem::take(&mut lines),
+                    timestamps: std::mem::take(&mut timestamps),
+                });
+                block += 1;
+            }
+        } else {
+            let (timestamp, text) = parse_timestamp(line);
+            lines.push(text.to_string());
+            timestamps.push(timestamp);
+        }
+    }
+
+    if !lines.is_empty() {
+        documents.push(Document {
+            name: document_name(&stem, block),
+            lines,
+            timestamps,
+        });
+    }
+
+    Ok(documents)
+}
+
+/// Strips a leading `[mm:ss.mmm]` karaoke cue from `line`, if present,
+/// returning the parsed offset and the remaining text.
+fn parse_timestamp(line: &str) -> (Option<Duration>, &str) {
+    let Some(rest) = line.strip_prefix('[') else {
+        return (None, line);
+    };
+    let Some(end) = rest.find(']') else {
+        return (None, line);
+    };
+
+    let tag = &rest[..end];
+    let text = rest[end + 1..].trim_start();
+
+    match parse_mm_ss_mmm(tag) {
+        Some(duration) => (Some(duration), text),
+        None => (None, line),
+    }
+}
+
+/// Parses a `mm:ss` or `mm:ss.fff` timestamp tag into a [`Duration`]. The
+/// fractional part is standard LRC-style, so it may be any number of digits
+/// (hundredths are the common case) and is scaled to milliseconds by its
+/// width rather than read as a literal millisecond count.
+fn parse_mm_ss_mmm(tag: &str) -> Option<Duration> {
+    let (minutes, rest) = tag.split_once(':')?;
+    let (seconds, millis) = match rest.split_once('.') {
+        Some((seconds, fraction)) => (seconds, parse_fraction_millis(fraction)?),
+        None => (rest, 0),
+    };
+
+    let minutes: u64 = minutes.parse().ok()?;
+    let seconds: u64 = seconds.parse().ok()?;
+
+    Some(Duration::from_millis(
+        (minutes * 60 + seconds) * 1000 + millis,
+    ))
+}
+
+/// Scales a fractional-seconds digit string to milliseconds, e.g. `"5"`
+/// (tenths) to `500` and `"50"` (hundredths) to `500`, rather than treating
+/// it as a literal millisecond count. Digits past the third (sub-millisecond
+/// precision) are truncated instead of rejected.
+fn parse_fraction_millis(fraction: &str) -> Option<u64> {
+    if fraction.is_empty() {
+        return None;
+    }
+    let fraction = &fraction[..fraction.len().min(3)];
+    let value: u64 = fraction.parse().ok()?;
+    Some(value * 10u64.pow(3 - fraction.len() as u32))
+}
+
+fn document_name(stem: &str, block: usize) -> String {
+    if block == 0 {
+        stem.to_string()
+    } else {
+        format!("{stem} #{}", block + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hundredths_fraction() {
+        assert_eq!(parse_mm_ss_mmm("00:01.50"), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn parses_tenths_fraction() {
+        assert_eq!(parse_mm_ss_mmm("00:01.5"), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn truncates_sub_millisecond_fraction() {
+        assert_eq!(parse_mm_ss_mmm("00:01.5000"), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn parses_milliseconds_fraction() {
+        assert_eq!(parse_mm_ss_mmm("00:01.500"), Some(Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn parses_tag_with_no_fraction() {
+        assert_eq!(parse_mm_ss_mmm("01:02"), Some(Duration::from_secs(62)));
+    }
+
+    #[test]
+    fn rejects_malformed_tag() {
+        assert_eq!(parse_mm_ss_mmm("not-a-tag"), None);
+    }
+
+    #[test]
+    fn strips_leading_timestamp_tag() {
+        let (ts, text) = parse_timestamp("[00:01.50] 안녕하세요");
+        assert_eq!(ts, Some(Duration::from_millis(1500)));
+        assert_eq!(text, "안녕하세요");
+    }
+
+    #[test]
+    fn untagged_line_passes_through_unchanged() {
+        let (ts, text) = parse_timestamp("안녕하세요");
+        assert_eq!(ts, None);
+        assert_eq!(text, "안녕하세요");
+    }
+
+    #[test]
+    fn has_timestamps_reflects_any_tagged_line() {
+        let doc = Document {
+            name: String::from("d"),
+            lines: vec![String::from("a"), String::from("b")],
+            timestamps: vec![None, Some(Duration::from_secs(1))],
+        };
+        assert!(doc.has_timestamps());
+    }
+}
@@ -1,5 +1,13 @@
-use std::io;
-use std::time::Duration;
+#[cfg(feature = "audio")]
+mod audio;
+mod corpus;
+mod stats;
+mod theme;
+mod timeline;
+
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crossterm::{
     cursor,
@@ -11,8 +19,17 @@ use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Gauge, Paragraph, Wrap},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+#[cfg(feature = "audio")]
+use audio::AudioPlayer;
+use corpus::Document;
+use stats::Stats;
+use theme::Theme;
+use timeline::Timeline;
 
-const LYRICS: [&str; 4] = [
+const FALLBACK_LYRICS: [&str; 4] = [
     "동해 물과 백두산이 마르고 닳도록",
     "하느님이 보우하사 우리나라 만세",
     "무궁화 삼천리 화려 강산",
@@ -29,50 +46,150 @@ enum StepResult {
 }
 
 struct Game {
-    text_chars: Vec<char>,
+    lines: Vec<String>,
+    text_graphemes: Vec<String>,
     char_meta: Vec<(usize, usize)>,
+    line_starts: Vec<usize>,
+    /// `cumulative_width[i]` is the total display width of the first `i`
+    /// graphemes, so progress can be reported in cells rather than counts.
+    cumulative_width: Vec<usize>,
     total_chars: usize,
     boss_hp: f32,
     boss_damage: f32,
     current_index: usize,
+    pending: String,
     awaiting_restart: bool,
     message: String,
+    stats: Stats,
+    timeline: Option<Timeline>,
+    session_start: Option<Instant>,
+    karaoke_line: Option<usize>,
+    karaoke_missed: usize,
 }
 
 impl Game {
-    fn new() -> Self {
-        let text_chars: Vec<char> = LYRICS.iter().flat_map(|line| line.chars()).collect();
-        let mut char_meta = Vec::with_capacity(text_chars.len());
-        for (line_idx, line) in LYRICS.iter().enumerate() {
-            for (pos, _) in line.chars().enumerate() {
+    fn new(lines: Vec<String>, timeline: Option<Timeline>) -> Self {
+        let text_graphemes: Vec<String> = lines
+            .iter()
+            .flat_map(|line| line.graphemes(true).map(String::from))
+            .collect();
+        let mut char_meta = Vec::with_capacity(text_graphemes.len());
+        let mut line_starts = Vec::with_capacity(lines.len());
+        for (line_idx, line) in lines.iter().enumerate() {
+            line_starts.push(char_meta.len());
+            for (pos, _) in line.graphemes(true).enumerate() {
                 char_meta.push((line_idx, pos));
             }
         }
 
-        let total_chars = text_chars.len();
+        let total_chars = text_graphemes.len();
         let boss_damage = 100.0 / total_chars as f32;
 
+        let mut cumulative_width = Vec::with_capacity(total_chars + 1);
+        cumulative_width.push(0);
+        for grapheme in &text_graphemes {
+            cumulative_width.push(cumulative_width.last().unwrap() + grapheme.width());
+        }
+
         Self {
-            text_chars,
+            lines,
+            text_graphemes,
             char_meta,
+            line_starts,
+            cumulative_width,
             total_chars,
             boss_hp: 100.0,
             boss_damage,
             current_index: 0,
+            pending: String::new(),
             awaiting_restart: false,
             message: String::from("가사를 모두 입력해 보스를 처치하세요."),
+            stats: Stats::new(),
+            timeline,
+            session_start: None,
+            karaoke_line: None,
+            karaoke_missed: 0,
         }
     }
 
     fn reset(&mut self) {
         self.boss_hp = 100.0;
         self.current_index = 0;
+        self.pending.clear();
         self.awaiting_restart = false;
         self.message = String::from("다시 시작했습니다. 계속 입력하세요.");
+        self.stats = Stats::new();
+        self.session_start = None;
+        self.karaoke_line = None;
+        self.karaoke_missed = 0;
     }
 
-    fn expected_char(&self) -> Option<char> {
-        self.text_chars.get(self.current_index).copied()
+    /// Anchors the karaoke clock at the start of a playthrough; a no-op
+    /// outside karaoke mode or once the clock is already running.
+    fn start_session(&mut self, now: Instant) {
+        if self.timeline.is_some() {
+            self.session_start.get_or_insert(now);
+        }
+    }
+
+    /// Advances karaoke state to match the wall clock, skipping the player
+    /// ahead (and counting a miss) if a line's window closed before they
+    /// finished typing it.
+    fn tick_karaoke(&mut self, now: Instant) {
+        let Some(timeline) = &self.timeline else {
+            return;
+        };
+        let Some(start) = self.session_start else {
+            return;
+        };
+        if self.awaiting_restart {
+            return;
+        }
+
+        let elapsed = now.saturating_duration_since(start);
+        let Some(active_line) = timeline.active_line(elapsed) else {
+            return;
+        };
+
+        if self.karaoke_line == Some(active_line) {
+            return;
+        }
+
+        let jump_to = self.line_starts[active_line];
+        if self.current_index < jump_to {
+            let (stalled_line, _) = self.line_state();
+            self.karaoke_missed += active_line - stalled_line;
+            let skipped = jump_to - self.current_index;
+            self.boss_hp = (self.boss_hp - self.boss_damage * skipped as f32).max(0.0);
+            self.current_index = jump_to;
+            self.pending.clear();
+        }
+
+        self.karaoke_line = Some(active_line);
+    }
+
+    /// Countdown until the active karaoke line's window closes, for the
+    /// countdown gauge in `draw_lyrics`.
+    fn karaoke_countdown(&self, now: Instant) -> Option<Duration> {
+        let timeline = self.timeline.as_ref()?;
+        let start = self.session_start?;
+        let elapsed = now.saturating_duration_since(start);
+        timeline.time_until_next(elapsed)
+    }
+
+    /// Total duration of the current karaoke line's window, for scaling the
+    /// countdown gauge in `draw_lyrics`.
+    fn karaoke_window(&self, now: Instant) -> Option<Duration> {
+        let timeline = self.timeline.as_ref()?;
+        let start = self.session_start?;
+        let elapsed = now.saturating_duration_since(start);
+        timeline.window_duration(elapsed)
+    }
+
+    fn expected_grapheme(&self) -> Option<&str> {
+        self.text_graphemes
+            .get(self.current_index)
+            .map(String::as_str)
     }
 
     fn process_char(&mut self, ch: char) -> StepResult {
@@ -88,8 +205,9 @@ impl Game {
             return StepResult::Ignored;
         }
 
-        let Some(expected) = self.expected_char() else {
+        let Some(expected) = self.expected_grapheme().map(str::to_string) else {
             self.awaiting_restart = true;
+            self.stats.finish(Instant::now());
             self.message = String::from("승리! 스페이스로 다시 시작합니다.");
             return StepResult::Victory;
         };
@@ -98,23 +216,33 @@ impl Game {
             return StepResult::Ignored;
         }
 
-        if ch.is_whitespace() && ch != ' ' && expected != ' ' {
+        if ch.is_whitespace() && ch != ' ' && expected != " " {
             return StepResult::Ignored;
         }
 
-        if ch == expected {
+        self.pending.push(ch);
+
+        if self.pending == expected {
+            self.pending.clear();
             self.current_index += 1;
             self.boss_hp = (self.boss_hp - self.boss_damage).max(0.0);
+            let now = Instant::now();
+            self.stats.record_correct(now);
 
             if self.current_index >= self.total_chars {
                 self.awaiting_restart = true;
+                self.stats.finish(now);
                 self.message = String::from("승리! 스페이스로 다시 시작합니다.");
                 StepResult::Victory
             } else {
                 self.message = String::from("정확!");
                 StepResult::Correct
             }
+        } else if expected.starts_with(self.pending.as_str()) {
+            StepResult::Ignored
         } else {
+            self.pending.clear();
+            self.stats.record_wrong(&expected, Instant::now());
             self.message = String::from("틀렸습니다.");
             StepResult::Wrong(ch)
         }
@@ -122,8 +250,8 @@ impl Game {
 
     fn line_state(&self) -> (usize, usize) {
         if self.current_index >= self.total_chars {
-            let idx = LYRICS.len() - 1;
-            (idx, LYRICS[idx].chars().count())
+            let idx = self.lines.len() - 1;
+            (idx, self.lines[idx].graphemes(true).count())
         } else {
             let (idx, typed_len) = self.char_meta[self.current_index];
             (idx, typed_len)
@@ -139,18 +267,153 @@ impl Game {
     }
 }
 
+/// Guards the terminal so raw mode and the alternate screen are always torn
+/// down when it drops, regardless of which path out of `run_app` was taken.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = restore_terminal();
+    }
+}
+
+/// Installs a panic hook that restores the terminal before handing off to
+/// whatever hook was previously registered, so a panic's report prints on a
+/// clean screen instead of inside raw mode.
+fn install_panic_hook() {
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        previous_hook(info);
+    }));
+}
+
 fn main() -> io::Result<()> {
+    install_panic_hook();
+
+    let (paths, audio_path, theme_override) = parse_args();
+    let document = select_document(&paths)?;
+    let timeline = document
+        .has_timestamps()
+        .then(|| Timeline::from_timestamps(&document.timestamps))
+        .flatten();
+
+    #[cfg(feature = "audio")]
+    let (audio_player, session_start) = {
+        let mut session_start = None;
+        let player = audio_path.as_deref().and_then(|path| {
+            let start = Instant::now();
+            match AudioPlayer::play_file(Path::new(path)) {
+                Ok(player) => {
+                    session_start = Some(start);
+                    Some(player)
+                }
+                Err(err) => {
+                    eprintln!("오디오 재생 실패({path}): {err}");
+                    None
+                }
+            }
+        });
+        (player, session_start)
+    };
+    #[cfg(not(feature = "audio"))]
+    let session_start: Option<Instant> = {
+        if audio_path.is_some() {
+            eprintln!("이 빌드는 오디오 재생 기능 없이 컴파일되었습니다 (--features audio 필요).");
+        }
+        None
+    };
+
     let mut terminal = setup_terminal()?;
+    let _guard = TerminalGuard;
+
+    let theme = theme_override.unwrap_or_else(|| theme::detect(Duration::from_millis(200)));
 
-    let app_result = run_app(&mut terminal);
+    let app_result = run_app(&mut terminal, document.lines, timeline, theme, session_start);
 
-    restore_terminal()?;
+    #[cfg(feature = "audio")]
+    if let Some(player) = &audio_player {
+        player.stop();
+    }
+    drop(_guard);
 
     app_result?;
     println!("게임을 종료합니다.");
     Ok(())
 }
 
+/// Splits command-line arguments into corpus file paths, an optional
+/// `--audio <path>` backing track, and an optional `--theme light|dark`
+/// override for the auto-detected color theme.
+fn parse_args() -> (Vec<String>, Option<String>, Option<Theme>) {
+    let mut paths = Vec::new();
+    let mut audio_path = None;
+    let mut theme_override = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--audio" => audio_path = args.next(),
+            "--theme" => {
+                theme_override = match args.next().as_deref() {
+                    Some("light") => Some(Theme::light()),
+                    Some("dark") => Some(Theme::dark()),
+                    _ => None,
+                };
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    (paths, audio_path, theme_override)
+}
+
+/// Loads documents from the given paths (or from `./corpus` if none were
+/// given), prompting the player to choose when more than one is available,
+/// and falls back to the built-in poem if no document was found. A corpus
+/// file that cannot be read is returned as an error instead of falling back.
+fn select_document(paths: &[String]) -> io::Result<Document> {
+    let documents = corpus::load_documents(paths, Path::new(corpus::DEFAULT_CORPUS_DIR))?;
+
+    if documents.is_empty() {
+        return Ok(Document {
+            name: String::from("fallback"),
+            lines: FALLBACK_LYRICS.iter().map(|line| line.to_string()).collect(),
+            timestamps: vec![None; FALLBACK_LYRICS.len()],
+        });
+    }
+
+    if documents.len() == 1 {
+        return Ok(documents.into_iter().next().unwrap());
+    }
+
+    prompt_document_choice(&documents)
+}
+
+/// Asks the player to pick a document by number before the terminal is put
+/// into raw mode, so normal line-buffered stdin works.
+fn prompt_document_choice(documents: &[Document]) -> io::Result<Document> {
+    loop {
+        println!("연습할 글을 선택하세요:");
+        for (idx, doc) in documents.iter().enumerate() {
+            println!("  {}) {}", idx + 1, doc.name);
+        }
+        print!("> ");
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+
+        if let Ok(choice) = input.trim().parse::<usize>() {
+            if choice >= 1 && choice <= documents.len() {
+                return Ok(documents[choice - 1].clone());
+            }
+        }
+
+        println!("1부터 {}까지의 번호를 입력하세요.", documents.len());
+    }
+}
+
 fn setup_terminal() -> io::Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -167,14 +430,22 @@ fn restore_terminal() -> io::Result<()> {
     Ok(())
 }
 
-fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
-    let mut game = Game::new();
+fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    lines: Vec<String>,
+    timeline: Option<Timeline>,
+    theme: Theme,
+    session_start: Option<Instant>,
+) -> io::Result<()> {
+    let mut game = Game::new(lines, timeline);
     game.message = String::from("실시간 입력 활성화. 가사를 이어서 입력하세요.");
+    game.start_session(session_start.unwrap_or_else(Instant::now));
 
     let mut wrong_char: Option<char> = None;
 
     loop {
-        terminal.draw(|f| draw_ui(f, &game, wrong_char))?;
+        terminal.draw(|f| draw_ui(f, &game, wrong_char, &theme))?;
+        game.tick_karaoke(Instant::now());
 
         if !event::poll(Duration::from_millis(250))? {
             continue;
@@ -223,7 +494,7 @@ fn key_to_char(code: &KeyCode) -> Option<char> {
     }
 }
 
-fn draw_ui(f: &mut Frame, game: &Game, wrong_char: Option<char>) {
+fn draw_ui(f: &mut Frame, game: &Game, wrong_char: Option<char>, theme: &Theme) {
     let area = centered_rect(70, 85, f.size());
 
     let layout = Layout::default()
@@ -233,14 +504,14 @@ fn draw_ui(f: &mut Frame, game: &Game, wrong_char: Option<char>) {
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Min(5),
-            Constraint::Length(3),
+            Constraint::Length(4),
         ])
         .split(area);
 
-    draw_header(f, layout[0]);
-    draw_stats(f, layout[1], game);
-    draw_lyrics(f, layout[2], game, wrong_char);
-    draw_messages(f, layout[3], game);
+    draw_header(f, layout[0], theme);
+    draw_stats(f, layout[1], game, theme);
+    draw_lyrics(f, layout[2], game, wrong_char, theme);
+    draw_messages(f, layout[3], game, theme);
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -269,11 +540,11 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-fn draw_header(f: &mut Frame, area: Rect) {
+fn draw_header(f: &mut Frame, area: Rect, theme: &Theme) {
     let text = vec![Line::from(Span::styled(
         "Mk.04 Rust Typing Practice",
         Style::default()
-            .fg(Color::White)
+            .fg(theme.title_fg)
             .add_modifier(Modifier::BOLD),
     ))];
 
@@ -284,70 +555,144 @@ fn draw_header(f: &mut Frame, area: Rect) {
     f.render_widget(para, area);
 }
 
-fn draw_stats(f: &mut Frame, area: Rect, game: &Game) {
+fn draw_stats(f: &mut Frame, area: Rect, game: &Game, theme: &Theme) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .constraints([
+            Constraint::Percentage(45),
+            Constraint::Percentage(25),
+            Constraint::Percentage(30),
+        ])
         .split(area);
 
     let hp_ratio = (clamp_percent(game.boss_hp) / 100.0).clamp(0.0, 1.0);
     let hp_gauge = Gauge::default()
         .block(Block::default().borders(Borders::ALL).title("보스 체력"))
-        .gauge_style(Style::default().fg(Color::Green))
+        .gauge_style(Style::default().fg(theme.hp_fg))
         .ratio(hp_ratio as f64)
         .label(format!("{:>5.1}%", clamp_percent(game.boss_hp)));
     f.render_widget(hp_gauge, chunks[0]);
 
-    let progress_percent = if game.total_chars == 0 {
+    let typed_width = game.cumulative_width[game.current_index];
+    let total_width = *game.cumulative_width.last().unwrap();
+    let progress_percent = if total_width == 0 {
         0.0
     } else {
-        (game.current_index as f32 / game.total_chars as f32) * 100.0
+        (typed_width as f32 / total_width as f32) * 100.0
     };
     let progress_ratio = (clamp_percent(progress_percent) / 100.0).clamp(0.0, 1.0);
     let progress = Gauge::default()
         .block(Block::default().borders(Borders::ALL).title("진행도"))
-        .gauge_style(Style::default().fg(Color::Cyan))
+        .gauge_style(Style::default().fg(theme.progress_fg))
         .ratio(progress_ratio as f64)
         .label(format!(
             "{:>5.1}% ({}/{})",
             clamp_percent(progress_percent),
-            game.current_index,
-            game.total_chars
+            typed_width,
+            total_width
         ));
     f.render_widget(progress, chunks[1]);
+
+    draw_typing_stats(f, chunks[2], game);
+}
+
+fn draw_typing_stats(f: &mut Frame, area: Rect, game: &Game) {
+    let now = Instant::now();
+    let gross = game.stats.gross_wpm(now);
+    let net = game.stats.net_wpm(now);
+    let accuracy = game.stats.accuracy() * 100.0;
+
+    let mut line = format!("WPM {:>3.0}/{:>3.0}  정확도 {:>5.1}%", gross, net, accuracy);
+    if game.timeline.is_some() {
+        line.push_str(&format!("  놓친 줄 {}", game.karaoke_missed));
+    }
+    let text = vec![Line::from(line)];
+
+    let para = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("타수"))
+        .wrap(Wrap { trim: true });
+    f.render_widget(para, area);
 }
 
-fn draw_lyrics(f: &mut Frame, area: Rect, game: &Game, wrong_char: Option<char>) {
+fn draw_lyrics(f: &mut Frame, area: Rect, game: &Game, wrong_char: Option<char>, theme: &Theme) {
     let (line_idx, typed_len) = game.line_state();
-    let current_line = LYRICS[line_idx];
+    let current_line = game.lines[line_idx].as_str();
+    let graphemes: Vec<&str> = current_line.graphemes(true).collect();
+
+    let typed_width: usize = graphemes[..typed_len.min(graphemes.len())]
+        .iter()
+        .map(|g| g.width())
+        .sum();
+    let total_width: usize = graphemes.iter().map(|g| g.width()).sum();
 
     let header_line = Line::from(vec![
         Span::styled(
-            format!("현재 줄 {}/{}", line_idx + 1, LYRICS.len()),
-            Style::default().fg(Color::Yellow),
+            format!("현재 줄 {}/{}", line_idx + 1, game.lines.len()),
+            Style::default().fg(theme.label_fg),
         ),
         Span::raw("   "),
-        Span::raw(format!(
-            "위치 {}/{}",
-            typed_len,
-            current_line.chars().count()
-        )),
+        Span::raw(format!("위치 {}/{}", typed_width, total_width)),
     ]);
 
     let lines = vec![
         header_line,
-        styled_line(current_line, typed_len, wrong_char),
+        styled_line(current_line, typed_len, wrong_char, theme),
     ];
 
+    let block = Block::default().borders(Borders::ALL).title("가사 진행");
+
+    let Some(countdown) = game.karaoke_countdown(Instant::now()) else {
+        let para = Paragraph::new(lines)
+            .block(block)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false });
+        f.render_widget(para, area);
+        return;
+    };
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(2), Constraint::Length(1)])
+        .split(inner);
+
     let para = Paragraph::new(lines)
-        .block(Block::default().borders(Borders::ALL).title("가사 진행"))
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: false });
-    f.render_widget(para, area);
+    f.render_widget(para, rows[0]);
+
+    let window = game
+        .karaoke_window(Instant::now())
+        .unwrap_or(Duration::from_secs(5));
+    let ratio = (countdown.as_secs_f64() / window.as_secs_f64()).clamp(0.0, 1.0);
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(theme.countdown_fg))
+        .ratio(ratio)
+        .label(format!("다음 줄까지 {:.1}초", countdown.as_secs_f64()));
+    f.render_widget(gauge, rows[1]);
 }
 
-fn draw_messages(f: &mut Frame, area: Rect, game: &Game) {
-    let text = vec![Line::from(game.message.as_str())];
+fn draw_messages(f: &mut Frame, area: Rect, game: &Game, theme: &Theme) {
+    let mut text = vec![Line::from(Span::styled(
+        game.message.as_str(),
+        Style::default().fg(theme.title_fg),
+    ))];
+
+    let top_misses = game.stats.top_misses(3);
+    if !top_misses.is_empty() {
+        let summary = top_misses
+            .iter()
+            .map(|(grapheme, count)| format!("{grapheme}×{count}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        text.push(Line::from(Span::styled(
+            format!("자주 틀린 글자: {summary}"),
+            Style::default().fg(theme.untyped_fg),
+        )));
+    }
 
     let para = Paragraph::new(text)
         .block(Block::default().borders(Borders::ALL).title("메시지"))
@@ -357,34 +702,28 @@ fn draw_messages(f: &mut Frame, area: Rect, game: &Game) {
 }
 
 fn clamp_percent(value: f32) -> f32 {
-    if value < 0.0 {
-        0.0
-    } else if value > 100.0 {
-        100.0
-    } else {
-        value
-    }
+    value.clamp(0.0, 100.0)
 }
 
-fn styled_line(line: &str, typed_len: usize, wrong_char: Option<char>) -> Line<'static> {
+fn styled_line(line: &str, typed_len: usize, wrong_char: Option<char>, theme: &Theme) -> Line<'static> {
     let mut spans: Vec<Span<'static>> = Vec::new();
 
-    for (idx, ch) in line.chars().enumerate() {
+    for (idx, grapheme) in line.graphemes(true).enumerate() {
         let style = if idx < typed_len {
-            Style::default().fg(Color::Green)
+            Style::default().fg(theme.correct_fg)
         } else if idx == typed_len {
             if wrong_char.is_some() {
-                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                Style::default().fg(theme.wrong_fg).add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(theme.current_fg)
                     .add_modifier(Modifier::BOLD)
             }
         } else {
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(theme.untyped_fg)
         };
 
-        spans.push(Span::styled(ch.to_string(), style));
+        spans.push(Span::styled(grapheme.to_string(), style));
     }
 
     Line::from(spans)
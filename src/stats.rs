@@ -0,0 +1,172 @@
+//! Per-session typing statistics: words-per-minute, accuracy, and a
+//! per-grapheme miss tally used to surface the keys the player fumbles most.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Tracks keystroke outcomes for the current attempt so the UI can report
+/// WPM, accuracy, and the most-missed graphemes.
+pub struct Stats {
+    started_at: Option<Instant>,
+    finished_at: Option<Instant>,
+    correct: usize,
+    wrong: usize,
+    miss_counts: HashMap<String, usize>,
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Stats {
+    pub fn new() -> Self {
+        Self {
+            started_at: None,
+            finished_at: None,
+            correct: 0,
+            wrong: 0,
+            miss_counts: HashMap::new(),
+        }
+    }
+
+    /// Records a correctly typed grapheme, starting the clock if this is the
+    /// first keystroke of the attempt.
+    pub fn record_correct(&mut self, now: Instant) {
+        self.started_at.get_or_insert(now);
+        self.correct += 1;
+    }
+
+    /// Records a mistyped keystroke against the grapheme that was expected.
+    pub fn record_wrong(&mut self, expected: &str, now: Instant) {
+        self.started_at.get_or_insert(now);
+        self.wrong += 1;
+        *self.miss_counts.entry(expected.to_string()).or_insert(0) += 1;
+    }
+
+    /// Stops the clock so `gross_wpm`/`net_wpm` report a fixed final figure
+    /// instead of decaying toward zero while the player reads their results.
+    /// A no-op if the attempt already finished.
+    pub fn finish(&mut self, now: Instant) {
+        self.finished_at.get_or_insert(now);
+    }
+
+    fn elapsed_minutes(&self, now: Instant) -> f64 {
+        match self.started_at {
+            Some(start) => (self.finished_at.unwrap_or(now) - start).as_secs_f64() / 60.0,
+            None => 0.0,
+        }
+    }
+
+    /// Gross words-per-minute, counting every correct keystroke.
+    pub fn gross_wpm(&self, now: Instant) -> f64 {
+        let minutes = self.elapsed_minutes(now);
+        if minutes <= 0.0 {
+            return 0.0;
+        }
+        (self.correct as f64 / 5.0) / minutes
+    }
+
+    /// Net words-per-minute: gross WPM with wrong keystrokes penalized.
+    pub fn net_wpm(&self, now: Instant) -> f64 {
+        let minutes = self.elapsed_minutes(now);
+        if minutes <= 0.0 {
+            return 0.0;
+        }
+        (self.gross_wpm(now) - (self.wrong as f64 / minutes)).max(0.0)
+    }
+
+    /// Fraction of keystrokes that were correct, `1.0` before any keystroke.
+    pub fn accuracy(&self) -> f64 {
+        let total = self.correct + self.wrong;
+        if total == 0 {
+            1.0
+        } else {
+            self.correct as f64 / total as f64
+        }
+    }
+
+    /// The most-missed graphemes, highest miss count first.
+    pub fn top_misses(&self, n: usize) -> Vec<(&str, usize)> {
+        let mut misses: Vec<(&str, usize)> = self
+            .miss_counts
+            .iter()
+            .map(|(grapheme, count)| (grapheme.as_str(), *count))
+            .collect();
+        misses.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        misses.truncate(n);
+        misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn accuracy_is_perfect_before_any_keystroke() {
+        assert_eq!(Stats::new().accuracy(), 1.0);
+    }
+
+    #[test]
+    fn accuracy_reflects_correct_and_wrong_counts() {
+        let mut stats = Stats::new();
+        let now = Instant::now();
+        stats.record_correct(now);
+        stats.record_correct(now);
+        stats.record_wrong("가", now);
+        assert!((stats.accuracy() - (2.0 / 3.0)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn gross_wpm_counts_correct_keystrokes_over_time() {
+        let mut stats = Stats::new();
+        let start = Instant::now();
+        stats.record_correct(start);
+        for _ in 0..4 {
+            stats.record_correct(start);
+        }
+        let later = start + Duration::from_secs(60);
+        // 5 correct keystrokes in one minute is exactly 1 word per minute.
+        assert!((stats.gross_wpm(later) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn net_wpm_is_never_negative() {
+        let mut stats = Stats::new();
+        let start = Instant::now();
+        stats.record_correct(start);
+        for _ in 0..20 {
+            stats.record_wrong("가", start);
+        }
+        let later = start + Duration::from_secs(60);
+        assert!(stats.net_wpm(later) >= 0.0);
+    }
+
+    #[test]
+    fn finish_freezes_wpm_against_further_elapsed_time() {
+        let mut stats = Stats::new();
+        let start = Instant::now();
+        stats.record_correct(start);
+        for _ in 0..4 {
+            stats.record_correct(start);
+        }
+        stats.finish(start + Duration::from_secs(60));
+        let wpm_at_finish = stats.gross_wpm(start + Duration::from_secs(60));
+        let wpm_later = stats.gross_wpm(start + Duration::from_secs(600));
+        assert_eq!(wpm_at_finish, wpm_later);
+    }
+
+    #[test]
+    fn top_misses_are_sorted_by_count_descending() {
+        let mut stats = Stats::new();
+        let now = Instant::now();
+        stats.record_wrong("가", now);
+        stats.record_wrong("나", now);
+        stats.record_wrong("나", now);
+        let top = stats.top_misses(1);
+        assert_eq!(top, vec![("나", 2)]);
+    }
+}
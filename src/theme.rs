@@ -0,0 +1,164 @@
+//! Detects whether the terminal background is light or dark and picks a
+//! matching color [`Theme`], so the hardcoded `Color::White`/`DarkGray`
+//! palette doesn't wash out on light terminals.
+
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+use ratatui::style::Color;
+
+/// Colors used throughout the UI in place of inline `Style::default().fg(...)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub title_fg: Color,
+    pub label_fg: Color,
+    pub untyped_fg: Color,
+    pub correct_fg: Color,
+    pub current_fg: Color,
+    pub wrong_fg: Color,
+    pub hp_fg: Color,
+    pub progress_fg: Color,
+    pub countdown_fg: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            title_fg: Color::White,
+            label_fg: Color::Yellow,
+            untyped_fg: Color::DarkGray,
+            correct_fg: Color::Green,
+            current_fg: Color::Yellow,
+            wrong_fg: Color::Red,
+            hp_fg: Color::Green,
+            progress_fg: Color::Cyan,
+            countdown_fg: Color::Magenta,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            title_fg: Color::Black,
+            label_fg: Color::Blue,
+            untyped_fg: Color::Gray,
+            correct_fg: Color::Green,
+            current_fg: Color::Blue,
+            wrong_fg: Color::Red,
+            hp_fg: Color::Green,
+            progress_fg: Color::Blue,
+            countdown_fg: Color::Magenta,
+        }
+    }
+}
+
+/// Auto-detects the terminal background via OSC 11 and picks the matching
+/// theme, falling back to dark if no reply arrives within `timeout`.
+///
+/// The terminal must already be in raw mode so the reply isn't echoed and
+/// arrives byte-at-a-time instead of waiting for a newline.
+pub fn detect(timeout: Duration) -> Theme {
+    match query_background_rgb(timeout) {
+        Some(rgb) if relative_luminance(rgb) > 0.5 => Theme::light(),
+        _ => Theme::dark(),
+    }
+}
+
+/// Queries the terminal background and reads its reply inline, bounded by
+/// `timeout`. This deliberately avoids a background reader thread: both
+/// `crossterm::event::poll` (used here) and a raw `stdin.read` would race
+/// over the same fd if one of them kept running past the deadline, and
+/// `poll` only checks readiness without consuming bytes, so it composes
+/// safely with the direct reads below.
+fn query_background_rgb(timeout: Duration) -> Option<(u8, u8, u8)> {
+    print!("\x1b]11;?\x07");
+    io::stdout().flush().ok()?;
+
+    let deadline = Instant::now() + timeout;
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    let mut stdin = io::stdin();
+
+    while response.len() < 64 {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() || !crossterm::event::poll(remaining).ok()? {
+            return None;
+        }
+
+        match stdin.read(&mut byte) {
+            Ok(1) => {
+                response.push(byte[0]);
+                if byte[0] == 0x07 || response.ends_with(&[0x1b, b'\\']) {
+                    break;
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    parse_osc11_reply(&response)
+}
+
+/// Parses a `...rgb:R.../G.../B...` OSC 11 reply into 8-bit components. Each
+/// channel may carry anywhere from 1 to 4 hex digits depending on the
+/// terminal, so the parsed value is rescaled from its actual bit depth
+/// rather than assumed to always be 16-bit.
+fn parse_osc11_reply(bytes: &[u8]) -> Option<(u8, u8, u8)> {
+    let text = String::from_utf8_lossy(bytes);
+    let tail = &text[text.find("rgb:")? + 4..];
+    let mut components = tail.splitn(3, '/');
+
+    let take = |s: &str| -> Option<u8> {
+        let hex: String = s.chars().take_while(|c| c.is_ascii_hexdigit()).collect();
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(&hex, 16).ok()?;
+        let max = 16u32.pow(hex.len() as u32) - 1;
+        Some(((value * 255) / max) as u8)
+    };
+
+    Some((
+        take(components.next()?)?,
+        take(components.next()?)?,
+        take(components.next()?)?,
+    ))
+}
+
+/// Perceptual luminance in `[0, 1]`; above `0.5` reads as a light background.
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    (0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64) / 255.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_16_bit_channels() {
+        let reply = b"\x1b]11;rgb:ffff/0000/0000\x07";
+        assert_eq!(parse_osc11_reply(reply), Some((255, 0, 0)));
+    }
+
+    #[test]
+    fn parses_8_bit_channels() {
+        let reply = b"\x1b]11;rgb:ff/80/00\x07";
+        assert_eq!(parse_osc11_reply(reply), Some((255, 128, 0)));
+    }
+
+    #[test]
+    fn parses_4_bit_channels() {
+        let reply = b"\x1b]11;rgb:f/8/0\x07";
+        assert_eq!(parse_osc11_reply(reply), Some((255, 136, 0)));
+    }
+
+    #[test]
+    fn rejects_reply_without_rgb_tag() {
+        assert_eq!(parse_osc11_reply(b"\x1b]11;garbage\x07"), None);
+    }
+
+    #[test]
+    fn luminance_above_half_reads_as_light() {
+        assert!(relative_luminance((255, 255, 255)) > 0.5);
+        assert!(relative_luminance((0, 0, 0)) < 0.5);
+    }
+}
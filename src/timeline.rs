@@ -0,0 +1,119 @@
+//! Drives karaoke mode: maps a wall-clock offset to the line that should be
+//! active right now, from the `[mm:ss.mmm]` cues parsed in [`crate::corpus`].
+
+use std::time::Duration;
+
+/// A sorted list of `(cue time, line index)` pairs used to look up which
+/// line is active as the session clock advances.
+pub struct Timeline {
+    entries: Vec<(Duration, usize)>,
+}
+
+impl Timeline {
+    /// Builds a timeline from a document's per-line timestamps, or returns
+    /// `None` if the document has no cues at all.
+    pub fn from_timestamps(timestamps: &[Option<Duration>]) -> Option<Self> {
+        let mut entries: Vec<(Duration, usize)> = timestamps
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, ts)| ts.map(|ts| (ts, idx)))
+            .collect();
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        entries.sort_by_key(|(ts, _)| *ts);
+        Some(Self { entries })
+    }
+
+    /// The line whose cue has most recently passed, if any cue has fired yet.
+    pub fn active_line(&self, elapsed: Duration) -> Option<usize> {
+        self.entries
+            .iter()
+            .take_while(|(ts, _)| *ts <= elapsed)
+            .last()
+            .map(|(_, idx)| *idx)
+    }
+
+    /// Time remaining until the line after `elapsed`'s active line takes
+    /// over, i.e. the countdown shown for the current line's window.
+    pub fn time_until_next(&self, elapsed: Duration) -> Option<Duration> {
+        self.entries
+            .iter()
+            .find(|(ts, _)| *ts > elapsed)
+            .map(|(ts, _)| *ts - elapsed)
+    }
+
+    /// Total duration of the window `elapsed` currently falls in, i.e. the
+    /// gap between the active line's cue and the next one. `None` if there
+    /// is no next cue (the last line never closes).
+    pub fn window_duration(&self, elapsed: Duration) -> Option<Duration> {
+        let (next_ts, _) = self.entries.iter().find(|(ts, _)| *ts > elapsed)?;
+        let start = self
+            .entries
+            .iter()
+            .take_while(|(ts, _)| *ts <= elapsed)
+            .last()
+            .map(|(ts, _)| *ts)
+            .unwrap_or(Duration::ZERO);
+        Some(*next_ts - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Timeline {
+        Timeline::from_timestamps(&[
+            Some(Duration::from_secs(0)),
+            Some(Duration::from_secs(2)),
+            None,
+            Some(Duration::from_secs(5)),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn no_cues_yields_no_timeline() {
+        assert!(Timeline::from_timestamps(&[None, None]).is_none());
+    }
+
+    #[test]
+    fn active_line_is_none_before_first_cue() {
+        let timeline = Timeline::from_timestamps(&[Some(Duration::from_secs(1))]).unwrap();
+        assert_eq!(timeline.active_line(Duration::from_secs(0)), None);
+    }
+
+    #[test]
+    fn active_line_tracks_most_recent_cue() {
+        let timeline = sample();
+        assert_eq!(timeline.active_line(Duration::from_secs(1)), Some(0));
+        assert_eq!(timeline.active_line(Duration::from_secs(3)), Some(1));
+        assert_eq!(timeline.active_line(Duration::from_secs(5)), Some(3));
+    }
+
+    #[test]
+    fn time_until_next_counts_down_to_the_following_cue() {
+        let timeline = sample();
+        assert_eq!(
+            timeline.time_until_next(Duration::from_secs(1)),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(timeline.time_until_next(Duration::from_secs(5)), None);
+    }
+
+    #[test]
+    fn window_duration_spans_the_active_and_next_cue() {
+        let timeline = sample();
+        assert_eq!(
+            timeline.window_duration(Duration::from_secs(1)),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            timeline.window_duration(Duration::from_secs(3)),
+            Some(Duration::from_secs(3))
+        );
+    }
+}